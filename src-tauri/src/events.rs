@@ -0,0 +1,108 @@
+//! Event and streaming IPC primitives exported to Python.
+//!
+//! Alongside the synchronous `greet`-style commands, Python handlers need to
+//! push data to the frontend without waiting on a reply: `emit`/`listen` for
+//! one-shot JSON events, and a `Channel`-backed stream for incremental
+//! results (e.g. token-by-token output from an Agno agent) that would be
+//! wasteful to send one IPC round trip per chunk. Everything here is safe to
+//! call from a Python background task without the GIL, which matters since
+//! `ext_mod` is declared `gil_used = false`.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use pyo3::prelude::*;
+use pytauri::AppHandle;
+use serde_json::Value;
+use tauri::{ipc::Channel, Emitter, Listener, Manager};
+
+fn parse_payload(payload: &str) -> PyResult<Value> {
+    serde_json::from_str(payload)
+        .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("invalid JSON payload: {err}")))
+}
+
+/// `ext_mod.emit(app_handle, event, payload, window=None)`
+///
+/// Emit `event` with a JSON `payload` to `window` (by label), or broadcast
+/// it to every window if `window` is `None`. `payload` is parsed so
+/// listeners receive the decoded object, not a JSON-encoded string.
+#[pyfunction]
+#[pyo3(signature = (app_handle, event, payload, window=None))]
+pub fn emit(app_handle: AppHandle, event: String, payload: String, window: Option<String>) -> PyResult<()> {
+    let payload = parse_payload(&payload)?;
+    match window {
+        Some(label) => {
+            let window = app_handle
+                .0
+                .get_webview_window(&label)
+                .ok_or_else(|| pyo3::exceptions::PyLookupError::new_err(format!("no window named `{label}`")))?;
+            window.emit(&event, payload)?;
+        }
+        None => app_handle.0.emit(&event, payload)?,
+    }
+    Ok(())
+}
+
+/// `ext_mod.listen(app_handle, event, callback) -> int`
+///
+/// Register `callback(payload: str)` to run on the Tauri event loop every
+/// time the webview emits `event`. Returns a listener id that can later be
+/// passed to [`unlisten`].
+#[pyfunction]
+pub fn listen(app_handle: AppHandle, event: String, callback: PyObject) -> u32 {
+    app_handle.0.listen(event, move |tauri_event| {
+        Python::with_gil(|py| {
+            if let Err(err) = callback.call1(py, (tauri_event.payload(),)) {
+                err.print(py);
+            }
+        });
+    })
+}
+
+/// `ext_mod.unlisten(app_handle, listener_id)`
+#[pyfunction]
+pub fn unlisten(app_handle: AppHandle, listener_id: u32) {
+    app_handle.0.unlisten(listener_id);
+}
+
+/// Channels the frontend has opened via [`open_stream`], waiting to be
+/// claimed by Python. Keyed by the caller-chosen `topic` string.
+fn pending_channels() -> &'static Mutex<HashMap<String, Channel<Value>>> {
+    static CHANNELS: OnceLock<Mutex<HashMap<String, Channel<Value>>>> = OnceLock::new();
+    CHANNELS.get_or_init(Default::default)
+}
+
+/// Tauri command the frontend invokes once per stream, handing us the
+/// `Channel` it created. We stash it under `topic` until Python claims it
+/// with [`take_channel`].
+#[tauri::command]
+pub fn open_stream(topic: String, channel: Channel<Value>) {
+    pending_channels().lock().unwrap().insert(topic, channel);
+}
+
+/// `ext_mod.take_channel(topic) -> PyChannel | None`
+///
+/// Claim the `Channel` the frontend opened for `topic`, if any. Call
+/// `.send(payload)` on the result to push the next chunk; `None` means the
+/// frontend hasn't opened that stream (yet).
+#[pyfunction]
+pub fn take_channel(topic: String) -> Option<PyChannel> {
+    pending_channels().lock().unwrap().remove(&topic).map(PyChannel)
+}
+
+/// A `tauri::ipc::Channel` wrapped for Python.
+#[pyclass]
+pub struct PyChannel(Channel<Value>);
+
+#[pymethods]
+impl PyChannel {
+    /// Push one more JSON-encoded chunk to the frontend.
+    fn send(&self, payload: String) -> PyResult<()> {
+        let payload = parse_payload(&payload)?;
+        self.0
+            .send(payload)
+            .map_err(|err| pyo3::exceptions::PyIOError::new_err(err.to_string()))
+    }
+}
@@ -1,4 +1,12 @@
-use pyo3::prelude::*;
+use pyo3::{prelude::*, wrap_pyfunction};
+use tauri::{Emitter, Manager};
+
+mod events;
+mod menu;
+mod plugins;
+mod shortcuts;
+mod sidecar;
+pub mod shutdown;
 
 // The usual Tauri command for testing
 #[tauri::command]
@@ -19,15 +27,72 @@ pub mod ext_mod {
 
     #[pymodule_init]
     fn init(module: &Bound<'_, PyModule>) -> PyResult<()> {
+        // Event/streaming IPC primitives, callable as `ext_mod.emit(...)` etc.
+        module.add_function(wrap_pyfunction!(events::emit, module)?)?;
+        module.add_function(wrap_pyfunction!(events::listen, module)?)?;
+        module.add_function(wrap_pyfunction!(events::unlisten, module)?)?;
+        module.add_function(wrap_pyfunction!(events::take_channel, module)?)?;
+        module.add_class::<events::PyChannel>()?;
+
+        // Global hotkeys, callable as `ext_mod.register_shortcut(...)`.
+        module.add_function(wrap_pyfunction!(shortcuts::register_shortcut, module)?)?;
+        module.add_function(wrap_pyfunction!(shortcuts::unregister_shortcut, module)?)?;
+
+        // Sidecar/subprocess supervision, callable as `ext_mod.spawn_sidecar(...)`.
+        module.add_function(wrap_pyfunction!(sidecar::spawn_sidecar, module)?)?;
+
+        // Graceful shutdown, callable as `ext_mod.set_shutdown_hook(...)`.
+        module.add_function(wrap_pyfunction!(shutdown::set_shutdown_hook, module)?)?;
+
         pytauri::pymodule_export(
             module,
             // Maps to Python’s `context_factory`
             |_args, _kwargs| Ok(tauri_generate_context()),
             // Maps to Python’s `builder_factory`
-            |_args, _kwargs| {
-                let builder = tauri::Builder::default()
-                    .plugin(tauri_plugin_opener::init())
-                    .invoke_handler(tauri::generate_handler![greet]);
+            |_args, kwargs| {
+                // Optional `menu=`/`tray=` JSON specs from the Python app config.
+                let menu_spec = menu::extract_spec::<menu::MenuSpec>(kwargs, "menu")?;
+                let tray_spec = menu::extract_spec::<menu::TraySpec>(kwargs, "tray")?;
+                // Which Tauri plugins to enable (`plugins=[{"name": ..., "config": ...}, ...]`).
+                let plugins_spec = plugins::extract_spec(kwargs)?;
+
+                let mut builder = tauri::Builder::default()
+                    .invoke_handler(tauri::generate_handler![greet, events::open_stream])
+                    .on_window_event(|_window, event| {
+                        // The Python `on_shutdown` hook runs once, from `main()`,
+                        // after the whole app has actually exited — a single
+                        // window's `CloseRequested` doesn't necessarily mean
+                        // that (other windows, or the close getting prevented).
+                        if matches!(event, tauri::WindowEvent::CloseRequested { .. }) {
+                            sidecar::kill_all();
+                        }
+                    });
+
+                for entry in &plugins_spec.0 {
+                    if let Some(plugin) = plugins::init_plugin(entry) {
+                        builder = builder.plugin(plugin);
+                    }
+                }
+
+                // One handler for both the menu bar and the tray's menu, so a
+                // click only ever emits `MENU_EVENT` once (see `menu::build_tray`).
+                if menu_spec.is_some() || tray_spec.is_some() {
+                    builder = builder.on_menu_event(|app, event| {
+                        let _ = app.emit(menu::MENU_EVENT, event.id.0.clone());
+                    });
+                }
+
+                builder = builder.setup(move |app| {
+                    if let Some(menu_spec) = &menu_spec {
+                        let app_menu = menu::build_menu(app.handle(), menu_spec)?;
+                        app.set_menu(app_menu)?;
+                    }
+                    if let Some(tray_spec) = &tray_spec {
+                        menu::build_tray(app.handle(), tray_spec)?;
+                    }
+                    Ok(())
+                });
+
                 Ok(builder)
             },
         )
@@ -1,7 +1,7 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::{convert::Infallible, env::var, error::Error, path::PathBuf};
+use std::{any::Any, convert::Infallible, env::var, error::Error, panic, path::PathBuf, process::Command, time::Duration};
 
 use pyo3::wrap_pymodule;
 use pytauri::standalone::{
@@ -9,11 +9,27 @@ use pytauri::standalone::{
 };
 use tauri::utils::platform::resource_dir;
 
-use agno_desktop_lib::{ext_mod, tauri_generate_context};
+use agno_desktop_lib::{ext_mod, shutdown, tauri_generate_context};
 
-fn main() -> Result<Infallible, Box<dyn Error>> {
+/// How many times to restart the app after the interpreter panics, before
+/// giving up and surfacing the failure. Carried across restarts via
+/// [`RESTART_ATTEMPT_ENV`], since each restart is a fresh process.
+const MAX_INTERPRETER_RESTARTS: u32 = 3;
+
+/// Env var a restart passes to the process it spawns, so the attempt count
+/// survives the re-exec.
+const RESTART_ATTEMPT_ENV: &str = "AGNO_DESKTOP_RESTART_ATTEMPT";
+
+/// Exit codes `main()` can produce itself, beyond whatever the embedded
+/// Python script returns. Chosen to stay clear of the script's own range.
+mod exit_code {
+    /// The interpreter panicked and automatic restarts were exhausted.
+    pub const PANIC: i32 = 70;
+}
+
+fn python_interpreter_env() -> Result<PythonInterpreterEnv, Box<dyn Error>> {
     // Figure out if we’re running in dev mode (with `tauri dev`) or standalone
-    let py_env = if cfg!(dev) {
+    if cfg!(dev) {
         let venv_dir = var("VIRTUAL_ENV").map_err(|err| {
             format!(
                 "The app is running in tauri dev mode, \
@@ -21,22 +37,98 @@ fn main() -> Result<Infallible, Box<dyn Error>> {
                 or set the `VIRTUAL_ENV` environment variable: {err}",
             )
         })?;
-        PythonInterpreterEnv::Venv(PathBuf::from(venv_dir).into())
+        Ok(PythonInterpreterEnv::Venv(PathBuf::from(venv_dir).into()))
     } else {
         let context = tauri_generate_context();
         let resource_dir = resource_dir(context.package_info(), &tauri::Env::default())
             .map_err(|err| format!("failed to get resource dir: {err}"))?;
         let resource_dir = simplified(&resource_dir).to_owned();
-        PythonInterpreterEnv::Standalone(resource_dir.into())
-    };
+        Ok(PythonInterpreterEnv::Standalone(resource_dir.into()))
+    }
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
 
+/// Show a native "the app crashed" dialog. Used once restarts are
+/// exhausted, so the user doesn't just see the window disappear.
+fn show_fatal_error_dialog(message: &str) {
+    rfd::MessageDialog::new()
+        .set_title("Agno Desktop")
+        .set_description(format!("The app stopped unexpectedly and couldn't recover:\n\n{message}"))
+        .set_level(rfd::MessageLevel::Error)
+        .show();
+}
+
+/// Re-exec this binary for the next restart attempt. We don't try to
+/// recover in-process: re-initializing the embedded Python runtime after a
+/// panic caught across the pyo3/Tauri boundary (potentially while holding
+/// the GIL) is unreliable, so a fresh process is the only restart we trust.
+/// Callers must drop the panicked `PythonInterpreter` before calling this,
+/// so the replacement doesn't start up nested under a still-alive one.
+///
+/// On Unix this replaces the current process image outright (`exec`), so
+/// there's never more than one process alive at a time across restarts. On
+/// other platforms we can't replace the image, so we spawn the replacement
+/// and exit immediately without waiting on it, rather than blocking this
+/// process as its parent for the replacement's whole lifetime.
+fn restart_process(attempt: u32) -> Result<Infallible, Box<dyn Error>> {
+    let exe = std::env::current_exe()?;
+    let mut command = Command::new(exe);
+    command.args(std::env::args_os().skip(1)).env(RESTART_ATTEMPT_ENV, attempt.to_string());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // `exec` only returns on failure.
+        Err(Box::new(command.exec()))
+    }
+    #[cfg(not(unix))]
+    {
+        command.spawn()?;
+        std::process::exit(0);
+    }
+}
+
+fn main() -> Result<Infallible, Box<dyn Error>> {
+    let attempt: u32 = var(RESTART_ATTEMPT_ENV).ok().and_then(|value| value.parse().ok()).unwrap_or(0);
+
+    let py_env = python_interpreter_env()?;
     // Run the Python module (same as `python -m tauri_app`)
     let py_script = PythonScript::Module("tauri_app".into());
-
-    // Register the Rust extension module for Python
     let builder = PythonInterpreterBuilder::new(py_env, py_script, |py| wrap_pymodule!(ext_mod)(py));
     let interpreter = builder.build()?;
 
-    let exit_code = interpreter.run();
-    std::process::exit(exit_code);
+    // Isolate a Python-level panic from a clean quit: a caught panic is a
+    // crash that leads to a restart or the `PANIC` exit code, and
+    // `on_shutdown` only runs after a clean quit.
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| interpreter.run())) {
+        Ok(exit_code) => {
+            // Run this while `interpreter` (and the Python runtime it owns)
+            // is still alive and in scope, not after it's been dropped.
+            shutdown::run_shutdown_hook(Duration::from_secs(5));
+            std::process::exit(exit_code);
+        }
+        Err(panic) => {
+            let message = panic_message(&panic);
+            eprintln!("python interpreter panicked: {message}");
+            if attempt >= MAX_INTERPRETER_RESTARTS {
+                eprintln!("exhausted {MAX_INTERPRETER_RESTARTS} restart attempts, giving up");
+                show_fatal_error_dialog(&message);
+                std::process::exit(exit_code::PANIC);
+            }
+            eprintln!("restarting as a fresh process (attempt {}/{MAX_INTERPRETER_RESTARTS})", attempt + 1);
+            // Tear down the panicked interpreter before replacing this
+            // process, so the restart never starts up nested under it.
+            drop(interpreter);
+            restart_process(attempt + 1)
+        }
+    }
 }
@@ -0,0 +1,150 @@
+//! Declarative application menu and system-tray support, driven from Python.
+//!
+//! The Python side describes the menu/tray it wants as JSON (see [`MenuSpec`]
+//! and [`TraySpec`]) and passes it to `builder_factory` via keyword arguments.
+//! We turn that into the `tauri::menu`/`tauri::tray` builders and forward
+//! clicks back out as a regular Tauri event, so the frontend (or Python,
+//! once it's listening for it) can react to `MENU_EVENT` without either side
+//! needing to know about the other's menu implementation.
+
+use pyo3::{types::PyDict, Bound, PyResult};
+use serde::Deserialize;
+use tauri::{
+    menu::{CheckMenuItemBuilder, IsMenuItem, Menu, MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder},
+    tray::TrayIconBuilder,
+    AppHandle, Manager, Runtime,
+};
+
+/// Event emitted (to all windows) when a menu or tray item is clicked.
+/// The payload is the item's id.
+pub const MENU_EVENT: &str = "app://menu-event";
+
+/// One entry in a declarative menu tree, as sent from the Python side.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MenuItemSpec {
+    Separator,
+    Item {
+        id: String,
+        label: String,
+    },
+    Check {
+        id: String,
+        label: String,
+        #[serde(default)]
+        checked: bool,
+    },
+    Submenu {
+        label: String,
+        items: Vec<MenuItemSpec>,
+    },
+}
+
+/// The application's menu bar, as a flat list of top-level entries.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MenuSpec(pub Vec<MenuItemSpec>);
+
+/// A system-tray icon plus the menu it pops up.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TraySpec {
+    #[serde(default)]
+    pub tooltip: Option<String>,
+    #[serde(default)]
+    pub menu: Vec<MenuItemSpec>,
+}
+
+/// Pull a `key`-named spec out of `builder_factory`'s kwargs, where Python
+/// passes it as a JSON string. Returns `None` if the key wasn't supplied.
+pub fn extract_spec<T: serde::de::DeserializeOwned>(
+    kwargs: Option<&Bound<'_, PyDict>>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    let Some(kwargs) = kwargs else {
+        return Ok(None);
+    };
+    let Some(value) = kwargs.get_item(key)? else {
+        return Ok(None);
+    };
+    let json: String = value.extract()?;
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("invalid `{key}` spec: {err}")))
+}
+
+fn build_items<R: Runtime>(
+    app: &AppHandle<R>,
+    specs: &[MenuItemSpec],
+) -> tauri::Result<Vec<Box<dyn IsMenuItem<R>>>> {
+    let mut items: Vec<Box<dyn IsMenuItem<R>>> = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let item: Box<dyn IsMenuItem<R>> = match spec {
+            MenuItemSpec::Separator => Box::new(PredefinedMenuItem::separator(app)?),
+            MenuItemSpec::Item { id, label } => Box::new(MenuItemBuilder::with_id(id, label).build(app)?),
+            MenuItemSpec::Check { id, label, checked } => {
+                Box::new(CheckMenuItemBuilder::with_id(id, label).checked(*checked).build(app)?)
+            }
+            MenuItemSpec::Submenu { label, items: sub_specs } => {
+                let sub_items = build_items(app, sub_specs)?;
+                let sub_refs: Vec<&dyn IsMenuItem<R>> = sub_items.iter().map(Box::as_ref).collect();
+                Box::new(SubmenuBuilder::new(app, label).items(&sub_refs).build()?)
+            }
+        };
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// Build the application menu bar described by `spec`.
+pub fn build_menu<R: Runtime>(app: &AppHandle<R>, spec: &MenuSpec) -> tauri::Result<Menu<R>> {
+    let items = build_items(app, &spec.0)?;
+    let refs: Vec<&dyn IsMenuItem<R>> = items.iter().map(Box::as_ref).collect();
+    MenuBuilder::new(app).items(&refs).build()
+}
+
+/// Build and attach the system-tray icon described by `spec`.
+///
+/// Tray menu clicks are *not* handled here — they go through the same
+/// app-level `on_menu_event` handler as the regular menu bar (wired up by
+/// the caller whenever a menu or tray is configured), so a click only ever
+/// emits [`MENU_EVENT`] once.
+pub fn build_tray<R: Runtime>(app: &AppHandle<R>, spec: &TraySpec) -> tauri::Result<()> {
+    let items = build_items(app, &spec.menu)?;
+    let refs: Vec<&dyn IsMenuItem<R>> = items.iter().map(Box::as_ref).collect();
+    let menu = MenuBuilder::new(app).items(&refs).build()?;
+
+    let mut tray = TrayIconBuilder::new().menu(&menu);
+    if let Some(icon) = app.default_window_icon() {
+        tray = tray.icon(icon.clone());
+    }
+    if let Some(tooltip) = &spec.tooltip {
+        tray = tray.tooltip(tooltip);
+    }
+    tray.build(app)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::{PyDict, PyDictMethods};
+
+    use super::{extract_spec, MenuSpec};
+
+    #[test]
+    fn missing_key_returns_none() {
+        pyo3::Python::with_gil(|py| {
+            let kwargs = PyDict::new(py);
+            let spec = extract_spec::<MenuSpec>(Some(&kwargs), "menu").unwrap();
+            assert!(spec.is_none());
+        });
+    }
+
+    #[test]
+    fn invalid_json_is_a_value_error() {
+        pyo3::Python::with_gil(|py| {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("menu", "not json").unwrap();
+            let err = extract_spec::<MenuSpec>(Some(&kwargs), "menu").unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+    }
+}
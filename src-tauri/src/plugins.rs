@@ -0,0 +1,110 @@
+//! Declarative plugin registration, driven from Python configuration.
+//!
+//! Instead of hard-wiring which Tauri plugins get compiled into the
+//! builder, the Python `tauri_app` module supplies a `plugins=` list of
+//! `{name, config}` entries and we initialize only those. Toggling a
+//! capability (filesystem access, notifications, ...) becomes a config
+//! change on the Python side instead of a Rust recompile.
+
+use std::time::Duration;
+
+use pyo3::{types::PyDict, Bound, PyResult};
+use serde::Deserialize;
+use serde_json::Value;
+use tauri::{plugin::TauriPlugin, Runtime};
+
+use crate::{menu, shortcuts};
+
+/// One plugin to enable, as sent from the Python side.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginEntry {
+    pub name: String,
+    /// Plugin-specific config. Most of the plugins below don't need one.
+    #[serde(default)]
+    pub config: Value,
+}
+
+/// The `plugins=` spec from `builder_factory`'s kwargs: which plugins to enable.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginsSpec(pub Vec<PluginEntry>);
+
+impl Default for PluginsSpec {
+    fn default() -> Self {
+        // Preserve the behavior this builder had right before plugin
+        // registration became configurable: `opener` was hard-coded from
+        // the start, and `global-shortcut` was unconditionally wired in
+        // once global hotkeys were added. Neither was ever opt-in, so
+        // omitting `plugins=` entirely must keep both enabled.
+        Self(vec![
+            PluginEntry { name: "opener".into(), config: Value::Null },
+            PluginEntry { name: "global-shortcut".into(), config: Value::Null },
+        ])
+    }
+}
+
+/// Pull the `plugins` spec out of `builder_factory`'s kwargs, defaulting to
+/// `opener` + `global-shortcut` (the previous hard-coded behavior) if it's absent.
+pub fn extract_spec(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<PluginsSpec> {
+    Ok(menu::extract_spec::<PluginsSpec>(kwargs, "plugins")?.unwrap_or_default())
+}
+
+/// Initialize the Tauri plugin named by `entry.name`, if recognized.
+pub fn init_plugin<R: Runtime>(entry: &PluginEntry) -> Option<TauriPlugin<R>> {
+    match entry.name.as_str() {
+        "opener" => {
+            warn_if_config_ignored(entry);
+            Some(tauri_plugin_opener::init())
+        }
+        "fs" => {
+            warn_if_config_ignored(entry);
+            Some(tauri_plugin_fs::init())
+        }
+        "dialog" => {
+            warn_if_config_ignored(entry);
+            Some(tauri_plugin_dialog::init())
+        }
+        "notification" => {
+            warn_if_config_ignored(entry);
+            Some(tauri_plugin_notification::init())
+        }
+        "store" => Some(init_store_plugin(entry)),
+        "global-shortcut" => {
+            warn_if_config_ignored(entry);
+            Some(shortcuts::plugin())
+        }
+        other => {
+            eprintln!("unknown plugin `{other}` in `plugins` config, skipping");
+            None
+        }
+    }
+}
+
+/// `store`'s `config` can set `{"auto_save_ms": <u64>}` to override the
+/// plugin's default autosave interval; everything else is defaulted.
+fn init_store_plugin<R: Runtime>(entry: &PluginEntry) -> TauriPlugin<R> {
+    let mut builder = tauri_plugin_store::Builder::default();
+    if let Some(auto_save_ms) = entry.config.get("auto_save_ms").and_then(Value::as_u64) {
+        builder = builder.auto_save(Duration::from_millis(auto_save_ms));
+    }
+    builder.build()
+}
+
+/// Most plugins here take no config; warn instead of silently dropping it
+/// so a typo'd or misplaced config blob isn't mistaken for applied config.
+fn warn_if_config_ignored(entry: &PluginEntry) {
+    if !entry.config.is_null() {
+        eprintln!("plugin `{}` doesn't accept a `config` blob; the one supplied is ignored", entry.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract_spec;
+
+    #[test]
+    fn defaults_to_opener_and_global_shortcut_when_absent() {
+        let spec = extract_spec(None).unwrap();
+        let names: Vec<&str> = spec.0.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["opener", "global-shortcut"]);
+    }
+}
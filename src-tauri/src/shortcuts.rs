@@ -0,0 +1,81 @@
+//! OS-level global shortcuts, registrable from Python.
+//!
+//! Wraps `tauri_plugin_global_shortcut` so the Python `tauri_app` module can
+//! bind accelerator strings (e.g. `"Ctrl+Shift+Q"`) to callbacks without
+//! touching Rust. Shortcuts fire even when the app window is unfocused,
+//! enabling a "launch from anywhere" UX.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use pyo3::prelude::*;
+use pytauri::AppHandle;
+use tauri::Runtime;
+use tauri_plugin_global_shortcut::{Builder as ShortcutBuilder, GlobalShortcutExt, Shortcut, ShortcutState};
+
+/// Callbacks registered via [`register_shortcut`], keyed by the parsed
+/// `Shortcut` (not the raw accelerator string — `Shortcut::to_string()`
+/// normalizes modifiers/keycodes, so a string key would never match what
+/// the plugin handler looks up).
+fn callbacks() -> &'static Mutex<HashMap<Shortcut, PyObject>> {
+    static CALLBACKS: OnceLock<Mutex<HashMap<Shortcut, PyObject>>> = OnceLock::new();
+    CALLBACKS.get_or_init(Default::default)
+}
+
+fn parse_accelerator(accelerator: &str) -> PyResult<Shortcut> {
+    accelerator
+        .parse()
+        .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("invalid accelerator `{accelerator}`: {err}")))
+}
+
+/// Build the `tauri_plugin_global_shortcut` plugin, wired to dispatch a
+/// press to whichever Python callback [`register_shortcut`] registered for
+/// that accelerator.
+pub fn plugin<R: Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    ShortcutBuilder::new()
+        .with_handler(|_app, shortcut, event| {
+            if event.state() != ShortcutState::Pressed {
+                return;
+            }
+            let callback = callbacks().lock().unwrap().get(shortcut).cloned();
+            if let Some(callback) = callback {
+                Python::with_gil(|py| {
+                    if let Err(err) = callback.call0(py) {
+                        err.print(py);
+                    }
+                });
+            }
+        })
+        .build()
+}
+
+/// `ext_mod.register_shortcut(app_handle, accelerator, callback)`
+///
+/// Register `accelerator` (e.g. `"Ctrl+Shift+Q"`) as a global shortcut that
+/// invokes `callback()` on press, even while the app window is unfocused.
+#[pyfunction]
+pub fn register_shortcut(app_handle: AppHandle, accelerator: String, callback: PyObject) -> PyResult<()> {
+    let shortcut = parse_accelerator(&accelerator)?;
+    app_handle
+        .0
+        .global_shortcut()
+        .register(shortcut)
+        .map_err(|err| pyo3::exceptions::PyRuntimeError::new_err(err.to_string()))?;
+    callbacks().lock().unwrap().insert(shortcut, callback);
+    Ok(())
+}
+
+/// `ext_mod.unregister_shortcut(app_handle, accelerator)`
+#[pyfunction]
+pub fn unregister_shortcut(app_handle: AppHandle, accelerator: String) -> PyResult<()> {
+    let shortcut = parse_accelerator(&accelerator)?;
+    app_handle
+        .0
+        .global_shortcut()
+        .unregister(shortcut)
+        .map_err(|err| pyo3::exceptions::PyRuntimeError::new_err(err.to_string()))?;
+    callbacks().lock().unwrap().remove(&shortcut);
+    Ok(())
+}
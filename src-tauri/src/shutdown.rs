@@ -0,0 +1,65 @@
+//! Graceful shutdown support: lets Python register an `on_shutdown`
+//! callback that `main()` runs (with a bounded timeout) before the process
+//! actually exits, so Python gets a chance to flush state or persist app
+//! data instead of being torn down fire-and-forget.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Mutex, OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use pyo3::prelude::*;
+
+fn shutdown_hook() -> &'static Mutex<Option<PyObject>> {
+    static HOOK: OnceLock<Mutex<Option<PyObject>>> = OnceLock::new();
+    HOOK.get_or_init(Default::default)
+}
+
+/// `ext_mod.set_shutdown_hook(callback)`
+///
+/// Register `callback()` to run when the app is closing, giving Python a
+/// chance to flush state or persist data before the process exits.
+#[pyfunction]
+pub fn set_shutdown_hook(callback: PyObject) {
+    *shutdown_hook().lock().unwrap() = Some(callback);
+}
+
+/// Guards [`run_shutdown_hook`] so it only ever runs once, no matter how
+/// many exit paths call it.
+static HAS_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Run the registered shutdown hook, if any, allowing it up to `timeout` to
+/// finish before giving up and letting shutdown proceed anyway.
+///
+/// Safe to call from more than one exit path: only the first call actually
+/// runs the hook. Callers should only invoke this once the app is truly
+/// exiting (not, e.g., on a single window's `CloseRequested`, which can
+/// fire without the process exiting) and while the Python runtime the
+/// callback belongs to is still alive.
+pub fn run_shutdown_hook(timeout: Duration) {
+    if HAS_RUN.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let Some(callback) = shutdown_hook().lock().unwrap().clone() else {
+        return;
+    };
+
+    let (done_tx, done_rx) = mpsc::channel();
+    thread::spawn(move || {
+        Python::with_gil(|py| {
+            if let Err(err) = callback.call0(py) {
+                err.print(py);
+            }
+        });
+        let _ = done_tx.send(());
+    });
+
+    if done_rx.recv_timeout(timeout).is_err() {
+        eprintln!("on_shutdown callback did not finish within {timeout:?}; continuing shutdown");
+    }
+}
@@ -0,0 +1,207 @@
+//! Sidecar/subprocess supervision exposed to Python.
+//!
+//! Spawns bundled external binaries — resolved from the app's resource
+//! directory, the same directory `main()` already computes for standalone
+//! mode — streams their stdout/stderr back as events, restarts them on an
+//! unexpected exit with exponential backoff, and terminates them once any
+//! window receives a close request (see [`kill_all`]). This lets a Python-side Agno app run something like a
+//! local inference server or vector DB process alongside the GUI.
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use pyo3::prelude::*;
+use pytauri::AppHandle;
+use serde::Deserialize;
+use tauri::{AppHandle as TauriAppHandle, Emitter, Manager, Runtime};
+
+/// Emitted as `{name, stream: "stdout"|"stderr", line}` for every line a
+/// sidecar prints.
+pub const SIDECAR_OUTPUT_EVENT: &str = "app://sidecar-output";
+/// Emitted as `{name, code}` once a sidecar has exhausted its restarts (or
+/// exited cleanly).
+pub const SIDECAR_EXIT_EVENT: &str = "app://sidecar-exit";
+
+/// A sidecar to spawn, as sent from the Python side.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SidecarSpec {
+    /// Binary name, resolved relative to the app's resource directory.
+    pub name: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// How many times to restart after an unexpected exit; `0` disables restart.
+    #[serde(default = "default_max_restarts")]
+    pub max_restarts: u32,
+    /// Base backoff before the first restart attempt, doubled each retry.
+    #[serde(default = "default_backoff_ms")]
+    pub backoff_ms: u64,
+}
+
+fn default_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_restarts() -> u32 {
+    5
+}
+
+/// Sidecars currently running, keyed by name, so [`kill_all`] can terminate
+/// them on shutdown.
+fn running() -> &'static Mutex<HashMap<String, Arc<Mutex<Child>>>> {
+    static RUNNING: OnceLock<Mutex<HashMap<String, Arc<Mutex<Child>>>>> = OnceLock::new();
+    RUNNING.get_or_init(Default::default)
+}
+
+/// Set by [`kill_all`] and never cleared, so a supervisor thread
+/// that observes its sidecar die from that kill treats it as a clean stop
+/// instead of an unexpected exit to restart.
+static STOPPING: AtomicBool = AtomicBool::new(false);
+
+/// `ext_mod.spawn_sidecar(app_handle, spec_json)`
+///
+/// Spawn the bundled binary described by `spec_json` (a JSON-encoded
+/// [`SidecarSpec`]). Runs under supervision on a background thread: output
+/// is forwarded line-by-line as [`SIDECAR_OUTPUT_EVENT`], and an unexpected
+/// exit is retried with backoff up to `max_restarts` times before
+/// [`SIDECAR_EXIT_EVENT`] is emitted.
+#[pyfunction]
+pub fn spawn_sidecar(app_handle: AppHandle, spec_json: String) -> PyResult<()> {
+    let spec: SidecarSpec = serde_json::from_str(&spec_json)
+        .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("invalid sidecar spec: {err}")))?;
+    let resource_dir = app_handle
+        .0
+        .path()
+        .resource_dir()
+        .map_err(|err| pyo3::exceptions::PyRuntimeError::new_err(format!("failed to get resource dir: {err}")))?;
+    let binary_path = resource_dir.join(&spec.name);
+
+    let app = app_handle.0.clone();
+    thread::spawn(move || supervise(app, spec, binary_path));
+    Ok(())
+}
+
+fn supervise<R: Runtime>(app: TauriAppHandle<R>, spec: SidecarSpec, binary_path: PathBuf) {
+    let mut attempt = 0u32;
+    loop {
+        let code = run_once(&app, &spec, &binary_path);
+        running().lock().unwrap().remove(&spec.name);
+
+        if STOPPING.load(Ordering::SeqCst) {
+            // kill_all() (or run_once, once STOPPING is set) terminated this
+            // sidecar — that's a clean stop, not a crash to restart.
+            return;
+        }
+        if code == Some(0) || attempt >= spec.max_restarts {
+            let _ = app.emit(SIDECAR_EXIT_EVENT, serde_json::json!({ "name": spec.name, "code": code }));
+            return;
+        }
+        attempt += 1;
+        let backoff = spec.backoff_ms.saturating_mul(1u64 << (attempt - 1).min(16));
+        thread::sleep(Duration::from_millis(backoff));
+    }
+}
+
+fn run_once<R: Runtime>(app: &TauriAppHandle<R>, spec: &SidecarSpec, binary_path: &Path) -> Option<i32> {
+    let mut child = match Command::new(binary_path)
+        .args(&spec.args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => {
+            let _ = app.emit(
+                SIDECAR_OUTPUT_EVENT,
+                serde_json::json!({ "name": spec.name, "stream": "stderr", "line": format!("failed to spawn: {err}") }),
+            );
+            return None;
+        }
+    };
+
+    forward_lines(app.clone(), spec.name.clone(), "stdout", child.stdout.take());
+    forward_lines(app.clone(), spec.name.clone(), "stderr", child.stderr.take());
+
+    let child = Arc::new(Mutex::new(child));
+    running().lock().unwrap().insert(spec.name.clone(), child.clone());
+
+    loop {
+        if STOPPING.load(Ordering::SeqCst) {
+            // Also covers a child spawned after (or while) `kill_all` drained
+            // `running`, which that call never saw.
+            let _ = child.lock().unwrap().kill();
+            return None;
+        }
+        // Scoped so the lock is released before we sleep — otherwise
+        // `kill_all` would block on this mutex for up to the sleep duration.
+        let status = child.lock().unwrap().try_wait();
+        match status {
+            Ok(Some(status)) => return status.code(),
+            Ok(None) => thread::sleep(Duration::from_millis(50)),
+            Err(_) => return None,
+        }
+    }
+}
+
+fn forward_lines<R: Runtime>(
+    app: TauriAppHandle<R>,
+    name: String,
+    stream: &'static str,
+    pipe: Option<impl std::io::Read + Send + 'static>,
+) {
+    let Some(pipe) = pipe else { return };
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            let _ = app.emit(
+                SIDECAR_OUTPUT_EVENT,
+                serde_json::json!({ "name": name, "stream": stream, "line": line }),
+            );
+        }
+    });
+}
+
+/// Terminate every sidecar still running. Called whenever any window
+/// receives `CloseRequested`, even if the close is then prevented or other
+/// windows stay open; an exit that doesn't go through a window close request
+/// doesn't call it. Once it has run, any sidecar spawned later is killed as
+/// soon as its supervisor sees it.
+pub fn kill_all() {
+    STOPPING.store(true, Ordering::SeqCst);
+    for (_, child) in running().lock().unwrap().drain() {
+        let _ = child.lock().unwrap().kill();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SidecarSpec;
+
+    #[test]
+    fn defaults_restart_with_backoff() {
+        let spec: SidecarSpec = serde_json::from_str(r#"{"name": "inference-server"}"#).unwrap();
+        assert_eq!(spec.name, "inference-server");
+        assert!(spec.args.is_empty());
+        assert_eq!(spec.max_restarts, 5);
+        assert_eq!(spec.backoff_ms, 500);
+    }
+
+    #[test]
+    fn explicit_fields_override_defaults() {
+        let spec: SidecarSpec = serde_json::from_str(
+            r#"{"name": "vector-db", "args": ["--port", "1234"], "max_restarts": 0, "backoff_ms": 50}"#,
+        )
+        .unwrap();
+        assert_eq!(spec.args, vec!["--port", "1234"]);
+        assert_eq!(spec.max_restarts, 0);
+        assert_eq!(spec.backoff_ms, 50);
+    }
+}